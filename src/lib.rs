@@ -12,7 +12,15 @@ number of visited entries is limited to `10k` and the maximum depth of traversal
 These limit can be changed with the methods [`max_entries`] and [`max_depth`].
 - `Entry` can be used to build objects that can be serialized e.g. as Json, due
 to it being in-memory.
-- Symbolic links are skipped.
+- Symbolic links are skipped, unless [`follow_links`] is enabled, in which case
+symlinked directories are descended into with cycle detection.
+- Behind the `serde` feature, [`Entry::to_serializable`] builds an owned
+[`SerializableEntry`] snapshot that actually implements `Serialize`/`Deserialize`,
+so a tree can be sent over the network and reconstructed on the other side.
+- By default a `read_dir` failure (e.g. a permission-denied directory) aborts
+the whole traversal. Enabling [`ignore_errors`] instead records the failure
+and keeps walking the rest of the tree, so a partially unreadable tree still
+yields a usable result.
 
 ## Use
 The entry point of this crate is the [`Walker`] (builder) struct. Use the [`new`] function
@@ -23,6 +31,15 @@ or directories during traversal.
 - The method [`skip_directories`] allows to skip directories.
 - Use [`max_depth`] to stop the traversal at a fixed depth.
 - Use [`max_entries`] to set the maximum number of visited entries during traversal.
+- Use [`follow_links`] to descend into symlinked directories instead of skipping them.
+- Use [`sort_by`] (or the [`sort_by_file_name`] shortcut) to replace the default
+directories-first alphabetic ordering with a custom comparator.
+- Use [`respect_gitignore`] to prune entries matching the `.gitignore` found in
+each visited directory, or [`add_ignore_file`] to apply a specific ignore file.
+- Use [`threads`] to traverse large trees across a pool of worker threads.
+- Use [`filter_entry`] to prune directories with an arbitrary predicate.
+- Use [`ignore_errors`] to keep walking past an unreadable directory instead
+of aborting the traversal, collecting what went wrong in [`Entry::errors`].
 
 All of the above are optional. After setting the options use [`walk_dir`]
 to traverse the file system starting from the `root`.
@@ -38,6 +55,15 @@ Alternatively a flat list of entries is available to the [`iterator`] of the
 [`skip_directories`]: struct.Walker.html#method.skip_directories
 [`max_depth`]: struct.Walker.html#method.max_depth
 [`max_entries`]: struct.Walker.html#method.max_entries
+[`follow_links`]: struct.Walker.html#method.follow_links
+[`sort_by`]: struct.Walker.html#method.sort_by
+[`sort_by_file_name`]: struct.Walker.html#method.sort_by_file_name
+[`respect_gitignore`]: struct.Walker.html#method.respect_gitignore
+[`add_ignore_file`]: struct.Walker.html#method.add_ignore_file
+[`threads`]: struct.Walker.html#method.threads
+[`filter_entry`]: struct.Walker.html#method.filter_entry
+[`ignore_errors`]: struct.Walker.html#method.ignore_errors
+[`Entry::errors`]: struct.Entry.html#structfield.errors
 [`walk_dir`]: struct.Walker.html#method.walk_dir
 [`dirent`]: struct.Value.html#structfield.dirent
 [`children`]: struct.Value.html#structfield.children
@@ -114,6 +140,25 @@ pub struct Walker {
     max_depth: usize,
     /// Maximum number of traversed entries
     max_entries: usize,
+    /// If true, symlinked directories are descended into instead of skipped
+    follow_links: bool,
+    /// If set, replaces the default "directories first, then alphabetic" ordering
+    comparator: Option<Box<dyn FnMut(&DirEntry, &DirEntry) -> std::cmp::Ordering>>,
+    /// If true, accumulate and apply the `.gitignore` found in each visited directory
+    respect_gitignore: bool,
+    /// Extra ignore files to apply from their containing directory, in addition to
+    /// any `.gitignore` picked up via `respect_gitignore`
+    ignore_files: Vec<PathBuf>,
+    /// Number of worker threads used to traverse the tree. `1` (the default)
+    /// performs the traversal on the calling thread
+    threads: usize,
+    /// If set, a directory for which this returns `false` is pruned and never descended into
+    filter_entry: Option<Box<dyn FnMut(&DirEntry) -> bool>>,
+    /// If true, a `read_dir` failure is recorded in `Entry::errors` instead of aborting the walk
+    ignore_errors: bool,
+    /// `read_dir` failures recorded so far when `ignore_errors` is set, plus any
+    /// symlink loops pruned by `follow_links`, which are recorded regardless
+    errors: Vec<(PathBuf, std::io::Error)>,
     _counter: usize,
 }
 
@@ -142,6 +187,14 @@ impl Walker {
             skip_directories: Default::default(),
             max_entries: 10_000,
             max_depth: 100,
+            follow_links: Default::default(),
+            comparator: None,
+            respect_gitignore: Default::default(),
+            ignore_files: Default::default(),
+            threads: 1,
+            filter_entry: None,
+            ignore_errors: Default::default(),
+            errors: Vec::new(),
             _counter: 0,
         }
     }
@@ -221,9 +274,196 @@ impl Walker {
         self
     }
 
+    /// Follow symlinked directories instead of skipping them.
+    ///
+    /// Visited directories are tracked by a stable identity (`(st_dev, st_ino)`
+    /// on Unix, a canonicalized-path-based identity on Windows) along the
+    /// current ancestor chain. If a followed link resolves to a directory
+    /// already on that chain, the link is treated as a dead end rather than
+    /// being recursed into, so a cycle (e.g. a symlink pointing back at an
+    /// ancestor) cannot hang the traversal or overflow the stack. The pruned
+    /// link is recorded in [`Entry::errors`] so the dead end is observable
+    /// instead of just silently yielding no children.
+    ///
+    /// # Arguments
+    ///
+    /// * `follow` - whether symlinked directories should be descended into
+    ///
+    /// [`Entry::errors`]: struct.Entry.html#structfield.errors
+    pub fn follow_links(mut self, follow: bool) -> Walker {
+        self.follow_links = follow;
+        self
+    }
+
+    /// Sort the entries of each directory with a custom comparator, replacing
+    /// the default "directories first, then alphabetic by path" ordering.
+    /// The comparator is applied to all entries of a directory merged together,
+    /// so it is also responsible for any directories-first grouping it wants.
+    ///
+    /// # Arguments
+    ///
+    /// `compare` - comparator used to order the entries of each directory
+    ///
+    /// # Example
+    /// ```
+    /// # use dir_walker::Walker;
+    /// let entries = Walker::new("./src")
+    ///     .sort_by(|a, b| b.file_name().cmp(&a.file_name()))
+    ///     .walk_dir()
+    ///     .unwrap();
+    /// ```
+    pub fn sort_by<F>(mut self, compare: F) -> Walker
+    where
+        F: FnMut(&DirEntry, &DirEntry) -> std::cmp::Ordering + 'static,
+    {
+        self.comparator = Some(Box::new(compare));
+        self
+    }
+
+    /// Sort the entries of each directory by file name rather than full path,
+    /// replacing the default "directories first, then alphabetic by path"
+    /// ordering. A convenience shortcut for [`sort_by`].
+    ///
+    /// [`sort_by`]: struct.Walker.html#method.sort_by
+    pub fn sort_by_file_name(self) -> Walker {
+        self.sort_by(|a, b| a.file_name().cmp(&b.file_name()))
+    }
+
+    /// Honor the `.gitignore` found in each visited directory, pruning matching
+    /// entries from the traversal. Patterns found deeper in the tree take
+    /// precedence over shallower ones, later patterns in a file override earlier
+    /// ones, and `!`-prefixed patterns re-include a previously ignored entry,
+    /// mirroring `git`'s own precedence rules. A directory that matches an
+    /// ignore rule is pruned without reading its children.
+    ///
+    /// # Arguments
+    ///
+    /// `respect` - whether `.gitignore` files should be honored
+    pub fn respect_gitignore(mut self, respect: bool) -> Walker {
+        self.respect_gitignore = respect;
+        self
+    }
+
+    /// Apply the ignore patterns in `path` (a gitignore-style ignore file, not
+    /// necessarily named `.gitignore`) to everything below the directory that
+    /// contains it, in addition to any `.gitignore` picked up via
+    /// [`respect_gitignore`]. Can be called multiple times to add several files.
+    ///
+    /// # Arguments
+    ///
+    /// `path` - path to a gitignore-style ignore file
+    ///
+    /// [`respect_gitignore`]: struct.Walker.html#method.respect_gitignore
+    pub fn add_ignore_file(mut self, path: impl AsRef<std::path::Path>) -> Walker {
+        self.ignore_files.push(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Traverse the file system across `n` worker threads instead of on the
+    /// calling thread alone. Each worker pops a directory off a shared work
+    /// queue, reads it, and pushes its subdirectories back onto the queue, which
+    /// speeds up traversal of deep or wide trees on spinning disks or networked
+    /// filesystems. The per-directory results are stitched back together once
+    /// every worker is done, so the resulting [`Entry`] tree has the same
+    /// deterministic ordering as the single-threaded traversal.
+    ///
+    /// [`max_entries`] is applied to the stitched tree rather than while
+    /// workers are still populating it, since truncating against a counter
+    /// shared between workers finishing in a nondeterministic order would
+    /// make the surviving subset nondeterministic too. This means a threaded
+    /// walk bounded by [`max_entries`] still reads every directory down to
+    /// [`max_depth`] before truncating, so it does not save the I/O a
+    /// single-threaded walk saves by stopping early; only the ordering
+    /// guarantee, not the I/O bound, carries over.
+    ///
+    /// When [`max_depth`] cuts the tree short, the two walks can differ: the
+    /// single-threaded walk still reads each directory at depth [`max_depth`],
+    /// counting its entries toward [`max_entries`] without keeping them and
+    /// recording a read failure or a symlink loop there in [`Entry::errors`],
+    /// while the threaded walk reads no directory at that depth. With both
+    /// limits binding, the threaded tree can therefore hold more entries.
+    ///
+    /// A custom comparator set with [`sort_by`] or a predicate set with
+    /// [`filter_entry`] cannot safely be shared across worker threads, so if
+    /// either is set, `walk_dir` falls back to the single-threaded traversal
+    /// regardless of this setting.
+    ///
+    /// # Arguments
+    ///
+    /// `n` - number of worker threads to use; values less than `1` are treated as `1`
+    ///
+    /// [`max_entries`]: struct.Walker.html#method.max_entries
+    /// [`max_depth`]: struct.Walker.html#method.max_depth
+    /// [`sort_by`]: struct.Walker.html#method.sort_by
+    /// [`filter_entry`]: struct.Walker.html#method.filter_entry
+    /// [`Entry::errors`]: struct.Entry.html#structfield.errors
+    pub fn threads(mut self, n: usize) -> Walker {
+        self.threads = n.max(1);
+        self
+    }
+
+    /// Prune entries with an arbitrary predicate. When `predicate` returns
+    /// `false` for a directory, that directory is never descended into, so its
+    /// subtree is never read, unlike [`skip_directories`] which only knows
+    /// about a fixed list of paths. Useful for pruning rules the crate has no
+    /// dedicated option for, such as by size, extension or modification time.
+    ///
+    /// # Arguments
+    ///
+    /// `predicate` - returns `true` to keep an entry, `false` to prune it
+    ///
+    /// # Example
+    /// ```
+    /// # use dir_walker::Walker;
+    /// let entries = Walker::new("./")
+    ///     .filter_entry(|e| e.file_name() != "target")
+    ///     .walk_dir()
+    ///     .unwrap();
+    /// ```
+    ///
+    /// [`skip_directories`]: struct.Walker.html#method.skip_directories
+    pub fn filter_entry<P>(mut self, predicate: P) -> Walker
+    where
+        P: FnMut(&DirEntry) -> bool + 'static,
+    {
+        self.filter_entry = Some(Box::new(predicate));
+        self
+    }
+
+    /// Select how a `read_dir` failure on a directory is handled.
+    /// By default (`false`) the first such failure aborts the whole traversal
+    /// and is returned as the `Err` of [`walk_dir`]. When set to `true`, the
+    /// failure is instead recorded in [`Entry::errors`] on the returned tree
+    /// and the traversal continues with that directory's siblings, so a tree
+    /// the process can't fully read still yields a usable partial result.
+    /// Other I/O failures during the walk, such as an entry whose metadata
+    /// can't be read, are not recorded in either mode: that entry is skipped.
+    ///
+    /// # Arguments
+    ///
+    /// `ignore` - whether directory read failures should be non-fatal
+    ///
+    /// # Example
+    /// ```
+    /// # use dir_walker::Walker;
+    /// let entries = Walker::new("./src")
+    ///     .ignore_errors(true)
+    ///     .walk_dir()
+    ///     .unwrap();
+    /// # assert!(entries.errors.is_empty());
+    /// ```
+    ///
+    /// [`walk_dir`]: struct.Walker.html#method.walk_dir
+    /// [`Entry::errors`]: struct.Entry.html#structfield.errors
+    pub fn ignore_errors(mut self, ignore: bool) -> Walker {
+        self.ignore_errors = ignore;
+        self
+    }
+
     /// Returns a recursive structure that represents the entries inside the `root` directory
     /// and its sub-directories in a depth first order, directories first and files last.
-    /// Symbolic links are skipped.
+    /// Symbolic links are skipped unless [`follow_links`] is set. A comparator
+    /// set with [`sort_by`] replaces the directories-first ordering.
     ///
     /// # Arguments
     ///
@@ -238,26 +478,64 @@ impl Walker {
     /// # let p = Path::new("./src").canonicalize().unwrap();
     /// # assert_eq!(dirent.path(), p);
     /// ```
+    ///
+    /// [`follow_links`]: struct.Walker.html#method.follow_links
+    /// [`sort_by`]: struct.Walker.html#method.sort_by
     pub fn walk_dir(&mut self) -> Result<Entry, std::io::Error> {
+        if self.threads > 1 && self.comparator.is_none() && self.filter_entry.is_none() {
+            return self.walk_dir_parallel();
+        }
+
         let root = self.root.canonicalize()?;
         let root_entry = get_parent_entry(&root)?;
 
-        let children = self.walk_dir_inner(&root, 0)?;
-        let entries = Entry::new(children, Some(root_entry), 0);
+        let mut visited = std::collections::HashSet::new();
+        if let Ok(identity) = entry_identity(&root) {
+            visited.insert(identity);
+        }
+
+        let mut ignore_rules = Vec::new();
+        for ignore_file in self.ignore_files.clone() {
+            let Ok(ignore_file) = ignore_file.canonicalize() else {
+                continue;
+            };
+            if let Some(base_dir) = ignore_file.parent() {
+                ignore_rules.extend(parse_ignore_file(&ignore_file, base_dir));
+            }
+        }
+
+        let children = self.walk_dir_inner(&root, 0, &mut visited, &mut ignore_rules)?;
+        let mut entries = Entry::new(children, Some(root_entry), 0);
+        entries.errors = std::mem::take(&mut self.errors);
 
         Ok(entries)
     }
 
     /// Returns a recursive structure that represents the children of the input path
     /// and its sub-directories. The structure is computed visiting directories and their
-    /// sub-directories.
+    /// sub-directories. `visited` tracks the identity of directories along the current
+    /// ancestor chain so that, when `follow_links` is set, a symlink cycle is detected
+    /// and treated as a dead end instead of being recursed into. `ignore_rules` accumulates
+    /// the gitignore-style rules in scope for `path`; rules picked up from `path`'s own
+    /// `.gitignore` are popped again once `path`'s subtree has been fully visited, so
+    /// siblings don't see them.
     fn walk_dir_inner(
         &mut self,
         path: impl AsRef<std::path::Path>,
         depth: usize,
+        visited: &mut std::collections::HashSet<EntryIdentity>,
+        ignore_rules: &mut Vec<IgnoreRule>,
     ) -> Result<Vec<Entry>, std::io::Error> {
+        let own_rule_count = ignore_rules.len();
+        if self.respect_gitignore {
+            let gitignore = path.as_ref().join(".gitignore");
+            if gitignore.is_file() {
+                ignore_rules.extend(parse_ignore_file(&gitignore, path.as_ref()));
+            }
+        }
+
         let mut children: Vec<Entry> = Vec::new();
-        let entries = self.read_entries(&path)?;
+        let entries = self.read_entries(&path, ignore_rules)?;
 
         for entry in entries.into_iter() {
             self._counter += 1;
@@ -267,70 +545,234 @@ impl Walker {
             }
 
             if depth <= self.max_depth {
-                children.push(Entry::new(
-                    self.walk_dir_inner(entry.path().as_path(), depth + 1)?,
-                    Some(entry),
-                    depth,
-                ));
+                let is_followed_link = self.follow_links && entry.path().is_symlink();
+                let identity = if is_followed_link || (self.follow_links && entry.path().is_dir())
+                {
+                    entry_identity(entry.path()).ok()
+                } else {
+                    None
+                };
+
+                // A followed link whose target is already on the current
+                // ancestor chain is a cycle: treat it as a dead end rather
+                // than recursing into it again.
+                let loop_detected =
+                    is_followed_link && identity.map_or(true, |id| visited.contains(&id));
+
+                let grandchildren = if loop_detected {
+                    self.errors.push((entry.path(), loop_detected_error(&entry.path())));
+                    Vec::new()
+                } else {
+                    if let Some(id) = identity {
+                        visited.insert(id);
+                    }
+
+                    let grandchildren = self.walk_dir_inner(
+                        entry.path().as_path(),
+                        depth + 1,
+                        visited,
+                        ignore_rules,
+                    )?;
+
+                    if let Some(id) = identity {
+                        visited.remove(&id);
+                    }
+
+                    grandchildren
+                };
+
+                children.push(Entry::new(grandchildren, Some(entry), depth));
 
                 if self._counter >= self.max_entries {
+                    ignore_rules.truncate(own_rule_count);
                     return Ok(children);
                 }
             }
         }
+        ignore_rules.truncate(own_rule_count);
         Ok(children)
     }
 
-    /// Returns a vector of directories and files in alphabetic order (directories first)
-    /// found in the given path.
+    /// Parallel counterpart of [`walk_dir`], used when [`threads`] is set above `1`.
+    /// See [`threads`] for the details of how work is distributed and the tree
+    /// stitched back together.
+    ///
+    /// [`walk_dir`]: struct.Walker.html#method.walk_dir
+    /// [`threads`]: struct.Walker.html#method.threads
+    fn walk_dir_parallel(&mut self) -> Result<Entry, std::io::Error> {
+        let root = self.root.canonicalize()?;
+        let root_entry = get_parent_entry(&root)?;
+
+        let mut ignore_rules = Vec::new();
+        for ignore_file in self.ignore_files.clone() {
+            let Ok(ignore_file) = ignore_file.canonicalize() else {
+                continue;
+            };
+            if let Some(base_dir) = ignore_file.parent() {
+                ignore_rules.extend(parse_ignore_file(&ignore_file, base_dir));
+            }
+        }
+
+        let mut ancestors = std::collections::HashSet::new();
+        if let Ok(identity) = entry_identity(&root) {
+            ancestors.insert(identity);
+        }
+
+        let shared = std::sync::Arc::new(ParallelShared {
+            queue: std::sync::Mutex::new(std::collections::VecDeque::from([ParallelJob {
+                path: root.clone(),
+                depth: 0,
+                ancestors,
+                ignore_rules,
+            }])),
+            condvar: std::sync::Condvar::new(),
+            pending: std::sync::atomic::AtomicUsize::new(1),
+            results: std::sync::Mutex::new(std::collections::HashMap::new()),
+            error: std::sync::Mutex::new(None),
+            errors: std::sync::Mutex::new(Vec::new()),
+            skip_dotted: self.skip_dotted,
+            skip_directories: self.skip_directories.clone(),
+            follow_links: self.follow_links,
+            respect_gitignore: self.respect_gitignore,
+            max_depth: self.max_depth,
+            ignore_errors: self.ignore_errors,
+        });
+
+        let handles: Vec<_> = (0..self.threads)
+            .map(|_| {
+                let shared = std::sync::Arc::clone(&shared);
+                std::thread::spawn(move || parallel_worker(&shared))
+            })
+            .collect();
+
+        for handle in handles {
+            let _ = handle.join();
+        }
+
+        let shared = std::sync::Arc::try_unwrap(shared)
+            .unwrap_or_else(|_| panic!("Error: a worker thread outlived the parallel walk"));
+
+        if let Some(error) = shared.error.into_inner().unwrap() {
+            return Err(error);
+        }
+
+        let mut results = shared.results.into_inner().unwrap();
+        let children = build_children_from_results(&root, 0, &mut results);
+        let mut counted = 0;
+        let children = truncate_to_max_entries(children, self.max_entries, &mut counted);
+
+        // `max_entries` truncation happens after the whole (unbounded) tree and its
+        // errors have already been collected, so an error recorded for a directory
+        // that didn't make the cut needs to be dropped too, or it would reference a
+        // path absent from the returned tree.
+        let mut kept_paths = std::collections::HashSet::new();
+        kept_paths.insert(root.clone());
+        collect_entry_paths(&children, &mut kept_paths);
+
+        let mut entries = Entry::new(children, Some(root_entry), 0);
+        entries.errors = shared
+            .errors
+            .into_inner()
+            .unwrap()
+            .into_iter()
+            .filter(|(path, _)| kept_paths.contains(path))
+            .collect();
+
+        Ok(entries)
+    }
+
+    /// Returns a vector of directories and files found in the given path, ordered
+    /// according to `comparator` if one is set, or alphabetically (directories first)
+    /// otherwise.
     fn read_entries(
-        &self,
+        &mut self,
         path: impl AsRef<std::path::Path>,
+        ignore_rules: &[IgnoreRule],
     ) -> Result<Vec<DirEntry>, std::io::Error> {
-        let mut paths: Vec<DirEntry> = Vec::new();
+        let mut dirs = self.get_entries(&path, true, ignore_rules)?;
+        let mut files = self.get_entries(&path, false, ignore_rules)?;
 
-        let mut dirs = self.get_entries(&path, true)?;
-        let mut files = self.get_entries(&path, false)?;
+        let mut paths: Vec<DirEntry> = Vec::with_capacity(dirs.len() + files.len());
 
-        paths.append(&mut dirs);
-        paths.append(&mut files);
+        if let Some(ref mut comparator) = self.comparator {
+            paths.append(&mut dirs);
+            paths.append(&mut files);
+            paths.sort_by(|a, b| comparator(a, b));
+        } else {
+            dirs.sort_by_key(|f| f.path());
+            files.sort_by_key(|f| f.path());
+            paths.append(&mut dirs);
+            paths.append(&mut files);
+        }
 
         Ok(paths)
     }
 
     /// Returns a vector of entries (as `DirEnt`) representing entries found inside the input `entry`.
     /// If `dirs_only` is true, this function returns directories, if false, it returns files.
-    /// Symblic links are skipped.
+    /// Symbolic links are skipped, unless `follow_links` is set, in which case a symlinked
+    /// directory is treated like a regular directory (its target's kind is what determines
+    /// whether it matches `dirs_only`). Entries matching `ignore_rules`, or rejected by
+    /// `filter_entry`, are pruned; for a rejected directory this prevents it from ever
+    /// being descended into.
+    ///
+    /// If `read_dir` fails and `ignore_errors` is set, the failure is recorded in
+    /// `self.errors` and this directory is treated as empty rather than aborting the
+    /// whole traversal. `read_entries` calls this twice per directory (once for
+    /// `dirs_only` true, once for false); the error is only recorded on the first
+    /// of those two calls, so it isn't duplicated in `self.errors`.
     fn get_entries(
-        &self,
+        &mut self,
         entry: impl AsRef<std::path::Path>,
         dirs_only: bool,
+        ignore_rules: &[IgnoreRule],
     ) -> Result<Vec<DirEntry>, std::io::Error> {
         let mut entries: Vec<DirEntry> = Vec::new();
         if entry.as_ref().is_dir() {
-            read_dir(entry)?
-                .filter_map(|e| e.ok())
-                .filter(|e| self.should_skip(e.path()))
-                .filter(|e| !e.path().is_symlink())
-                .filter(|e| {
+            let read_dir = match read_dir(&entry) {
+                Ok(read_dir) => read_dir,
+                Err(error) => {
+                    if !self.ignore_errors {
+                        return Err(error);
+                    }
                     if dirs_only {
-                        e.path().is_dir()
-                    } else {
-                        e.path().is_file()
+                        self.errors.push((entry.as_ref().to_path_buf(), error));
                     }
-                })
-                .for_each(|e| entries.push(e));
+                    return Ok(entries);
+                }
+            };
 
-            entries.sort_by_key(|f| f.path());
+            for e in read_dir.filter_map(|e| e.ok()) {
+                let kind_matches = if dirs_only {
+                    e.path().is_dir()
+                } else {
+                    e.path().is_file()
+                };
+
+                if kind_matches
+                    && (self.follow_links || !e.path().is_symlink())
+                    && self.should_skip(&e, ignore_rules)
+                {
+                    entries.push(e);
+                }
+            }
         }
 
         Ok(entries)
     }
 
-    fn should_skip(&self, path: impl AsRef<std::path::Path>) -> bool {
-        let path_str = path.as_ref().display().to_string();
-        !((self.skip_dotted & (path_str.contains("/.") | path_str.contains("\\.")))
-            | self.skip_directories.contains(&path.as_ref().to_path_buf()))
+    fn should_skip(&mut self, entry: &DirEntry, ignore_rules: &[IgnoreRule]) -> bool {
+        let path = entry.path();
+        let path_str = path.display().to_string();
+
+        let keep = !((self.skip_dotted & (path_str.contains("/.") | path_str.contains("\\.")))
+            | self.skip_directories.contains(&path)
+            | is_ignored(ignore_rules, &path));
+
+        match (keep, self.filter_entry.as_mut()) {
+            (true, Some(predicate)) => predicate(entry),
+            (keep, _) => keep,
+        }
     }
 }
 
@@ -344,6 +786,19 @@ pub struct Entry {
     pub children: Vec<Entry>,
     /// The depth of this entry with respect to the root.
     pub depth: usize,
+    /// Directories that failed to read during traversal, paired with the error
+    /// that was encountered. Only populated on the root `Entry` returned by
+    /// [`Walker::walk_dir`] when [`Walker::ignore_errors`] is set; otherwise
+    /// the first such failure aborts the traversal instead.
+    ///
+    /// Also records a symlink loop pruned by [`Walker::follow_links`] (keyed by
+    /// the link that would have recursed), regardless of [`Walker::ignore_errors`],
+    /// since pruning a loop isn't a failed traversal.
+    ///
+    /// [`Walker::walk_dir`]: struct.Walker.html#method.walk_dir
+    /// [`Walker::ignore_errors`]: struct.Walker.html#method.ignore_errors
+    /// [`Walker::follow_links`]: struct.Walker.html#method.follow_links
+    pub errors: Vec<(PathBuf, std::io::Error)>,
 }
 
 impl Entry {
@@ -352,6 +807,7 @@ impl Entry {
             children,
             dirent,
             depth,
+            errors: Vec::new(),
         }
     }
 
@@ -394,6 +850,106 @@ impl Entry {
         }
         None
     }
+
+    /// Builds an owned, serializable snapshot of this entry and its children.
+    ///
+    /// `Entry` borrows filesystem state through `std::fs::DirEntry`, which
+    /// implements neither `Clone` nor `Serialize`. This method calls
+    /// `DirEntry::metadata()` once per node to capture its size, modification
+    /// time and kind into a [`SerializableEntry`], which can be serialized
+    /// (e.g. to JSON) and sent over the network, then used to reconstruct the
+    /// tree on the receiving side without touching the filesystem again.
+    ///
+    /// # Example
+    /// ```
+    /// # use dir_walker::Walker;
+    /// let entries = Walker::new("./src").walk_dir().unwrap();
+    /// let snapshot = entries.to_serializable().unwrap();
+    /// assert_eq!(snapshot.file_name, "src");
+    /// ```
+    pub fn to_serializable(&self) -> Result<SerializableEntry, std::io::Error> {
+        let dirent = self.dirent.as_ref().ok_or_else(|| {
+            std::io::Error::new(std::io::ErrorKind::InvalidInput, "Error: entry has no dirent")
+        })?;
+        let metadata = dirent.metadata()?;
+
+        let children = self
+            .children
+            .iter()
+            .map(Entry::to_serializable)
+            .collect::<Result<Vec<SerializableEntry>, std::io::Error>>()?;
+
+        Ok(SerializableEntry {
+            path: dirent.path(),
+            file_name: dirent.file_name().to_string_lossy().to_string(),
+            is_dir: metadata.is_dir(),
+            len: metadata.len(),
+            modified: metadata.modified().ok(),
+            depth: self.depth,
+            children,
+        })
+    }
+
+    /// Returns a flat list of entries in contents-first order: a directory's
+    /// descendants are yielded before the directory entry itself, as opposed
+    /// to [`Entry::into_iter`] which yields a directory before its contents.
+    /// This is essential for use cases like recursive deletion or computing
+    /// aggregate directory sizes bottom-up.
+    ///
+    /// # Example
+    /// ```
+    /// # use dir_walker::{Walker, EntryItem};
+    /// let entries = Walker::new("./src").walk_dir().unwrap();
+    /// let items = entries
+    ///     .into_iter_contents_first()
+    ///     .collect::<Vec<EntryItem>>();
+    ///
+    /// // the root directory itself is drained last
+    /// # assert_eq!(items.last().unwrap().depth, 0);
+    /// ```
+    pub fn into_iter_contents_first(self) -> std::vec::IntoIter<EntryItem> {
+        let mut flat_vec: Vec<EntryItem> = Vec::new();
+        Entry::flatten_contents_first(self, &mut flat_vec);
+        flat_vec.into_iter()
+    }
+
+    /// Recursively appends `node`'s descendants to `out`, then `node` itself,
+    /// preserving the alphabetic/dirs-first ordering within each level.
+    fn flatten_contents_first(node: Entry, out: &mut Vec<EntryItem>) {
+        node.children
+            .into_iter()
+            .for_each(|child| Entry::flatten_contents_first(child, out));
+
+        if let Some(dirent) = node.dirent {
+            out.push(EntryItem::new(dirent, node.depth));
+        }
+    }
+}
+
+/// An owned, serializable snapshot of an [`Entry`] tree.
+///
+/// Unlike `Entry`, which borrows filesystem state through `std::fs::DirEntry`,
+/// this type copies out everything needed to describe a node, so it can be
+/// serialized (e.g. to JSON) and sent over the network, then deserialized
+/// again without touching the filesystem. Build one from an [`Entry`] with
+/// [`Entry::to_serializable`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SerializableEntry {
+    /// Full path of this entry.
+    pub path: PathBuf,
+    /// File name of this entry.
+    pub file_name: String,
+    /// Whether this entry is a directory.
+    pub is_dir: bool,
+    /// Size in bytes, as reported by `std::fs::Metadata::len`.
+    pub len: u64,
+    /// Last modification time, if the platform supports it.
+    pub modified: Option<std::time::SystemTime>,
+    /// Depth of this entry with respect to the root.
+    pub depth: usize,
+    /// Children of this entry, in the same order as `Entry::children`.
+    pub children: Vec<SerializableEntry>,
 }
 
 /// Helper type that is returned when iterating over an [`Entry`].
@@ -465,3 +1021,470 @@ fn get_parent_entry(path: &PathBuf) -> Result<DirEntry, std::io::Error> {
 
     root_entry
 }
+
+/// A stable identity used to recognize when two paths refer to the same
+/// underlying directory, so that following symlinks doesn't recurse forever.
+type EntryIdentity = (u64, u64);
+
+/// Returns a stable identity for `path`, following symlinks to the target.
+///
+/// On Unix this is `(st_dev, st_ino)` from `metadata()`. Windows has no
+/// direct equivalent without opening a file handle and querying
+/// `BY_HANDLE_FILE_INFORMATION` (the technique the `same_file` crate uses),
+/// so this falls back to a hash of the canonicalized path, which is enough
+/// to detect the common case of a symlink cycle.
+#[cfg(unix)]
+fn entry_identity(path: impl AsRef<std::path::Path>) -> Result<EntryIdentity, std::io::Error> {
+    use std::os::unix::fs::MetadataExt;
+    let metadata = std::fs::metadata(path)?;
+    Ok((metadata.dev(), metadata.ino()))
+}
+
+#[cfg(not(unix))]
+fn entry_identity(path: impl AsRef<std::path::Path>) -> Result<EntryIdentity, std::io::Error> {
+    use std::hash::{Hash, Hasher};
+
+    let canonical = path.as_ref().canonicalize()?;
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    canonical.hash(&mut hasher);
+    Ok((hasher.finish(), 0))
+}
+
+/// Builds the `io::Error` recorded in [`Entry::errors`] when [`follow_links`]
+/// hits a symlink that would recurse back into one of its own ancestor
+/// directories, so callers can observe that a cycle was pruned instead of the
+/// dead end going unremarked.
+///
+/// [`follow_links`]: struct.Walker.html#method.follow_links
+/// [`Entry::errors`]: struct.Entry.html#structfield.errors
+fn loop_detected_error(path: &std::path::Path) -> std::io::Error {
+    std::io::Error::new(
+        std::io::ErrorKind::Other,
+        format!("symlink loop detected, not following: {}", path.display()),
+    )
+}
+
+/// A compiled gitignore-style glob pattern, in the canonical form used for
+/// matching: relative to `base_dir`, with a leading `**/` already prepended
+/// if the original pattern was unanchored (i.e. could match at any depth).
+#[derive(Debug, Clone)]
+struct Glob {
+    pattern: String,
+}
+
+impl Glob {
+    fn new(pattern: String) -> Glob {
+        Glob { pattern }
+    }
+
+    /// Returns true if `rel_path` (a `/`-separated path relative to `base_dir`)
+    /// matches this glob.
+    fn is_match(&self, rel_path: &str) -> bool {
+        let pattern_segments = self.pattern.split('/').filter(|s| !s.is_empty());
+        let path_segments = rel_path.split('/').filter(|s| !s.is_empty());
+
+        glob_match_segments(
+            pattern_segments.collect::<Vec<_>>().as_slice(),
+            path_segments.collect::<Vec<_>>().as_slice(),
+        )
+    }
+}
+
+/// Matches a sequence of pattern segments against a sequence of path segments,
+/// where a `**` pattern segment matches zero or more path segments.
+fn glob_match_segments(pattern: &[&str], path: &[&str]) -> bool {
+    match pattern.first() {
+        None => path.is_empty(),
+        Some(&"**") => {
+            glob_match_segments(&pattern[1..], path)
+                || (!path.is_empty() && glob_match_segments(pattern, &path[1..]))
+        }
+        Some(segment) => match path.first() {
+            Some(head) => {
+                segment_match(segment, head) && glob_match_segments(&pattern[1..], &path[1..])
+            }
+            None => false,
+        },
+    }
+}
+
+/// Matches a single path segment against a single pattern segment containing
+/// `*` (any run of characters) and `?` (any single character) wildcards.
+fn segment_match(pattern: &str, text: &str) -> bool {
+    fn inner(pattern: &[char], text: &[char]) -> bool {
+        match (pattern.first(), text.first()) {
+            (None, None) => true,
+            (Some('*'), _) => {
+                inner(&pattern[1..], text) || (!text.is_empty() && inner(pattern, &text[1..]))
+            }
+            (Some('?'), Some(_)) => inner(&pattern[1..], &text[1..]),
+            (Some(p), Some(t)) if p == t => inner(&pattern[1..], &text[1..]),
+            _ => false,
+        }
+    }
+
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    inner(&pattern, &text)
+}
+
+/// A single compiled rule from a gitignore-style ignore file, in the order it
+/// was accumulated while descending the tree (shallower files first, patterns
+/// within a file in their original order).
+#[derive(Debug, Clone)]
+struct IgnoreRule {
+    /// The compiled glob, already expressed relative to `base_dir`
+    glob: Glob,
+    /// True for a `!`-prefixed pattern, which re-includes a previously ignored entry
+    is_negation: bool,
+    /// True for a pattern with a trailing `/`, which only matches directories
+    dir_only: bool,
+    /// The directory the ignore file that defined this rule was found in;
+    /// candidate paths are matched relative to this directory
+    base_dir: PathBuf,
+}
+
+/// Parses the gitignore-style ignore file at `path` into a list of rules whose
+/// patterns are resolved relative to `base_dir`. Unreadable files yield no rules.
+fn parse_ignore_file(path: &std::path::Path, base_dir: &std::path::Path) -> Vec<IgnoreRule> {
+    let Ok(content) = std::fs::read_to_string(path) else {
+        return Vec::new();
+    };
+
+    content
+        .lines()
+        .filter_map(|line| parse_ignore_line(line, base_dir))
+        .collect()
+}
+
+/// Parses a single line of a gitignore-style ignore file into a rule, or
+/// `None` for blank lines and comments.
+fn parse_ignore_line(line: &str, base_dir: &std::path::Path) -> Option<IgnoreRule> {
+    let line = line.trim_end();
+    if line.is_empty() || line.starts_with('#') {
+        return None;
+    }
+
+    let (is_negation, pattern) = match line.strip_prefix('!') {
+        Some(rest) => (true, rest),
+        None => (false, line),
+    };
+
+    let (dir_only, pattern) = match pattern.strip_suffix('/') {
+        Some(rest) => (true, rest),
+        None => (false, pattern),
+    };
+
+    if pattern.is_empty() {
+        return None;
+    }
+
+    // A pattern containing a slash other than a trailing one is anchored to
+    // `base_dir`; a pattern with no slash can match at any depth below it.
+    let anchored = pattern.contains('/');
+    let pattern = pattern.strip_prefix('/').unwrap_or(pattern);
+    let canonical = if anchored {
+        pattern.to_string()
+    } else {
+        format!("**/{pattern}")
+    };
+
+    Some(IgnoreRule {
+        glob: Glob::new(canonical),
+        is_negation,
+        dir_only,
+        base_dir: base_dir.to_path_buf(),
+    })
+}
+
+/// Returns true if `path` is ignored by `rules`, applying them in order so
+/// that later rules (including deeper ones, which are appended after
+/// shallower ones) override earlier ones, as `git` does.
+fn is_ignored(rules: &[IgnoreRule], path: &std::path::Path) -> bool {
+    let mut ignored = false;
+
+    for rule in rules {
+        if rule.dir_only && !path.is_dir() {
+            continue;
+        }
+
+        let Ok(rel_path) = path.strip_prefix(&rule.base_dir) else {
+            continue;
+        };
+        let rel_path = rel_path.to_string_lossy().replace('\\', "/");
+
+        if rule.glob.is_match(&rel_path) {
+            ignored = !rule.is_negation;
+        }
+    }
+
+    ignored
+}
+
+/// A unit of work for the parallel traversal: a directory still to be read,
+/// along with everything needed to process it independently of any other job.
+struct ParallelJob {
+    /// Directory to read
+    path: PathBuf,
+    /// Depth of this directory's own entries, mirroring `walk_dir_inner`
+    depth: usize,
+    /// Identity of the directories along the ancestor chain that led here,
+    /// used to detect symlink cycles when `follow_links` is set
+    ancestors: std::collections::HashSet<EntryIdentity>,
+    /// Ignore rules accumulated from shallower directories
+    ignore_rules: Vec<IgnoreRule>,
+}
+
+/// State shared by the worker threads of a parallel traversal.
+struct ParallelShared {
+    /// Directory jobs still to be processed
+    queue: std::sync::Mutex<VecDeque<ParallelJob>>,
+    /// Used to wake workers blocked on an empty queue when new jobs or the
+    /// end of the traversal are signaled
+    condvar: std::sync::Condvar,
+    /// Number of jobs that have been queued but not yet fully processed;
+    /// the traversal is done once this reaches zero
+    pending: std::sync::atomic::AtomicUsize,
+    /// Each visited directory's own (filtered, sorted) children, keyed by path,
+    /// stitched back into an `Entry` tree once the traversal is done. `max_entries`
+    /// is intentionally not enforced here: workers finish in a nondeterministic
+    /// order, so truncating while the tree is still scattered across this map
+    /// would make the surviving subset nondeterministic too. Instead the full,
+    /// `max_depth`-bounded tree is collected and `max_entries` is applied once
+    /// afterwards, in the same pre-order the single-threaded walk counts in.
+    results: std::sync::Mutex<std::collections::HashMap<PathBuf, Vec<DirEntry>>>,
+    /// First I/O error encountered by any worker, if any; only used when `ignore_errors` is false
+    error: std::sync::Mutex<Option<std::io::Error>>,
+    /// Directories that failed to read (only recorded when `ignore_errors` is true) and
+    /// symlink loops pruned by `follow_links` (recorded regardless of `ignore_errors`,
+    /// since a pruned loop isn't a failed traversal)
+    errors: std::sync::Mutex<Vec<(PathBuf, std::io::Error)>>,
+    skip_dotted: bool,
+    skip_directories: Vec<PathBuf>,
+    follow_links: bool,
+    respect_gitignore: bool,
+    max_depth: usize,
+    ignore_errors: bool,
+}
+
+/// Pops jobs off the shared queue and processes them until the traversal is
+/// done, i.e. the queue is empty and no job is still in flight.
+fn parallel_worker(shared: &ParallelShared) {
+    loop {
+        let job = {
+            let mut queue = shared.queue.lock().unwrap();
+            loop {
+                if let Some(job) = queue.pop_front() {
+                    break Some(job);
+                }
+                if shared.pending.load(std::sync::atomic::Ordering::SeqCst) == 0 {
+                    break None;
+                }
+                queue = shared.condvar.wait(queue).unwrap();
+            }
+        };
+
+        let Some(job) = job else {
+            break;
+        };
+
+        process_parallel_job(shared, job);
+
+        // Decrement under the queue lock so a worker can't check `pending`,
+        // miss this final notification, and then wait forever.
+        {
+            let _queue = shared.queue.lock().unwrap();
+            shared.pending.fetch_sub(1, std::sync::atomic::Ordering::SeqCst);
+        }
+        shared.condvar.notify_all();
+    }
+}
+
+/// Reads `job`'s directory, records its (filtered, sorted) children in
+/// `shared.results`, and queues a job for each child directory that should be
+/// descended into.
+fn process_parallel_job(shared: &ParallelShared, job: ParallelJob) {
+    let mut ignore_rules = job.ignore_rules;
+    if shared.respect_gitignore {
+        let gitignore = job.path.join(".gitignore");
+        if gitignore.is_file() {
+            ignore_rules.extend(parse_ignore_file(&gitignore, &job.path));
+        }
+    }
+
+    let dirs = parallel_get_entries(shared, &job.path, true, &ignore_rules);
+    let files = parallel_get_entries(shared, &job.path, false, &ignore_rules);
+
+    let (mut dirs, mut files) = match (dirs, files) {
+        (Ok(dirs), Ok(files)) => (dirs, files),
+        (Err(error), _) | (_, Err(error)) => {
+            if shared.ignore_errors {
+                shared.errors.lock().unwrap().push((job.path.clone(), error));
+            } else {
+                let mut error_slot = shared.error.lock().unwrap();
+                if error_slot.is_none() {
+                    *error_slot = Some(error);
+                }
+            }
+            (Vec::new(), Vec::new())
+        }
+    };
+
+    dirs.sort_by_key(|e| e.path());
+    files.sort_by_key(|e| e.path());
+
+    let mut sorted = Vec::with_capacity(dirs.len() + files.len());
+    sorted.append(&mut dirs);
+    sorted.append(&mut files);
+
+    let mut own_children = Vec::with_capacity(sorted.len());
+    let mut new_jobs = Vec::new();
+
+    for entry in sorted {
+        if job.depth + 1 <= shared.max_depth && entry.path().is_dir() {
+            let is_followed_link = shared.follow_links && entry.path().is_symlink();
+            let mut child_ancestors = job.ancestors.clone();
+            let mut loop_detected = false;
+
+            if is_followed_link {
+                match entry_identity(entry.path()) {
+                    Ok(identity) if !job.ancestors.contains(&identity) => {
+                        child_ancestors.insert(identity);
+                    }
+                    _ => loop_detected = true,
+                }
+            } else if shared.follow_links {
+                if let Ok(identity) = entry_identity(entry.path()) {
+                    child_ancestors.insert(identity);
+                }
+            }
+
+            if loop_detected {
+                shared
+                    .errors
+                    .lock()
+                    .unwrap()
+                    .push((entry.path(), loop_detected_error(&entry.path())));
+            } else {
+                new_jobs.push(ParallelJob {
+                    path: entry.path(),
+                    depth: job.depth + 1,
+                    ancestors: child_ancestors,
+                    ignore_rules: ignore_rules.clone(),
+                });
+            }
+        }
+
+        own_children.push(entry);
+    }
+
+    shared
+        .results
+        .lock()
+        .unwrap()
+        .insert(job.path.clone(), own_children);
+
+    if !new_jobs.is_empty() {
+        shared
+            .pending
+            .fetch_add(new_jobs.len(), std::sync::atomic::Ordering::SeqCst);
+        shared.queue.lock().unwrap().extend(new_jobs);
+        shared.condvar.notify_all();
+    }
+}
+
+/// Parallel counterpart of `Walker::get_entries`, operating on `shared`'s
+/// read-only configuration instead of `&self`.
+fn parallel_get_entries(
+    shared: &ParallelShared,
+    path: &std::path::Path,
+    dirs_only: bool,
+    ignore_rules: &[IgnoreRule],
+) -> Result<Vec<DirEntry>, std::io::Error> {
+    if !path.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let entries = read_dir(path)?
+        .filter_map(|e| e.ok())
+        .filter(|e| {
+            let path_str = e.path().display().to_string();
+            !((shared.skip_dotted & (path_str.contains("/.") | path_str.contains("\\.")))
+                | shared.skip_directories.contains(&e.path())
+                | is_ignored(ignore_rules, &e.path()))
+        })
+        .filter(|e| shared.follow_links || !e.path().is_symlink())
+        .filter(|e| {
+            if dirs_only {
+                e.path().is_dir()
+            } else {
+                e.path().is_file()
+            }
+        })
+        .collect();
+
+    Ok(entries)
+}
+
+/// Stitches the per-directory results of a parallel traversal back into the
+/// same recursive `Entry` structure `walk_dir_inner` would have produced.
+fn build_children_from_results(
+    path: &std::path::Path,
+    depth: usize,
+    results: &mut std::collections::HashMap<PathBuf, Vec<DirEntry>>,
+) -> Vec<Entry> {
+    let entries = results.remove(path).unwrap_or_default();
+
+    entries
+        .into_iter()
+        .map(|entry| {
+            let child_path = entry.path();
+            let grandchildren = build_children_from_results(&child_path, depth + 1, results);
+            Entry::new(grandchildren, Some(entry), depth)
+        })
+        .collect()
+}
+
+/// Applies `max_entries` to an already-built (and so far unbounded) tree,
+/// counting entries in the same pre-order `walk_dir_inner` uses: an entry is
+/// counted before its own children are, so the result is the same prefix of
+/// the depth-first, directories-first order the single-threaded walk would
+/// have produced, regardless of the order workers happened to finish in.
+/// Entries one level below `max_depth`, which `walk_dir_inner` counts without
+/// keeping, were never read here and so are not counted.
+fn truncate_to_max_entries(
+    entries: Vec<Entry>,
+    max_entries: usize,
+    counted: &mut usize,
+) -> Vec<Entry> {
+    let mut kept = Vec::with_capacity(entries.len());
+
+    for mut entry in entries {
+        *counted += 1;
+        if *counted == max_entries {
+            break;
+        }
+
+        let children = std::mem::take(&mut entry.children);
+        entry.children = truncate_to_max_entries(children, max_entries, counted);
+        kept.push(entry);
+
+        if *counted >= max_entries {
+            break;
+        }
+    }
+
+    kept
+}
+
+/// Recursively collects the `dirent` path of every entry in `entries` (and
+/// their descendants) into `out`, so a path absent from `out` is known to
+/// have been dropped by `truncate_to_max_entries`.
+fn collect_entry_paths(entries: &[Entry], out: &mut std::collections::HashSet<PathBuf>) {
+    for entry in entries {
+        if let Some(dirent) = &entry.dirent {
+            out.insert(dirent.path());
+        }
+        collect_entry_paths(&entry.children, out);
+    }
+}
@@ -98,6 +98,301 @@ fn should_stop_at_max_depth() {
     entries.into_iter().for_each(|e| assert!(e.depth <= 2));
 }
 
+#[test]
+fn should_build_serializable_snapshot() {
+    let entries = Walker::new("./src").walk_dir().unwrap();
+    let snapshot = entries.to_serializable().unwrap();
+
+    let target_entry = Path::new("./src").canonicalize().unwrap();
+    assert_eq!(snapshot.path, target_entry);
+    assert!(snapshot.is_dir);
+    assert_eq!(snapshot.children.len(), 1);
+
+    let lib_rs = &snapshot.children[0];
+    assert_eq!(lib_rs.file_name, "lib.rs");
+    assert!(!lib_rs.is_dir);
+}
+
+#[test]
+fn should_iterate_contents_first() {
+    let entries = Walker::new("./src").walk_dir().unwrap();
+    let items = entries
+        .into_iter_contents_first()
+        .collect::<Vec<EntryItem>>();
+
+    // "./src" has a single child, "./src/lib.rs", which must be drained
+    // before "./src" itself.
+    let lib_rs = Path::new("./src/lib.rs").canonicalize().unwrap();
+    let src = Path::new("./src").canonicalize().unwrap();
+
+    assert_eq!(items[0].dirent.path(), lib_rs);
+    assert_eq!(items[1].dirent.path(), src);
+}
+
+#[test]
+fn should_sort_with_custom_comparator() {
+    let dir = std::env::temp_dir().join("dir_walker_sort_by_test");
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("a.txt"), b"").unwrap();
+    std::fs::write(dir.join("b.txt"), b"").unwrap();
+    std::fs::write(dir.join("c.txt"), b"").unwrap();
+
+    let entries = Walker::new(&dir)
+        .sort_by(|a, b| b.file_name().cmp(&a.file_name()))
+        .walk_dir()
+        .unwrap();
+
+    let root = dir.canonicalize().unwrap();
+    let names = entries
+        .into_iter()
+        .filter(|e| e.dirent.path() != root)
+        .map(|e| e.dirent.file_name().to_string_lossy().to_string())
+        .collect::<Vec<String>>();
+
+    assert_eq!(names, vec!["c.txt", "b.txt", "a.txt"]);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn should_prune_with_filter_entry() {
+    let dir = std::env::temp_dir().join("dir_walker_filter_entry_test");
+    let pruned = dir.join("pruned");
+    let kept = dir.join("kept");
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&pruned).unwrap();
+    std::fs::create_dir_all(&kept).unwrap();
+    std::fs::write(pruned.join("inside.txt"), b"").unwrap();
+    std::fs::write(kept.join("inside.txt"), b"").unwrap();
+
+    let entries = Walker::new(&dir)
+        .filter_entry(|e| e.file_name() != "pruned")
+        .walk_dir()
+        .unwrap();
+
+    let paths = entries
+        .into_iter()
+        .map(|e| e.dirent.path())
+        .collect::<Vec<std::path::PathBuf>>();
+
+    assert!(!paths.contains(&pruned));
+    // "pruned" is never descended into, so its children are never read either.
+    assert!(!paths.contains(&pruned.join("inside.txt")));
+    assert!(paths.contains(&kept));
+    assert!(paths.contains(&kept.join("inside.txt")));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn should_produce_same_tree_with_threads() {
+    let mut sequential = Walker::new("./").skip_directories(&["./target"]).skip_dotted();
+    let mut parallel = Walker::new("./")
+        .skip_directories(&["./target"])
+        .skip_dotted()
+        .threads(4);
+
+    let sequential_paths = sequential
+        .walk_dir()
+        .unwrap()
+        .into_iter()
+        .map(|e| e.dirent.path())
+        .collect::<Vec<std::path::PathBuf>>();
+    let parallel_paths = parallel
+        .walk_dir()
+        .unwrap()
+        .into_iter()
+        .map(|e| e.dirent.path())
+        .collect::<Vec<std::path::PathBuf>>();
+
+    assert_eq!(sequential_paths, parallel_paths);
+}
+
+#[test]
+fn should_produce_same_truncated_tree_with_threads_and_max_entries() {
+    let max_entries = 40;
+    let mut sequential = Walker::new("./")
+        .skip_directories(&["./target"])
+        .skip_dotted()
+        .max_entries(max_entries);
+    let mut parallel = Walker::new("./")
+        .skip_directories(&["./target"])
+        .skip_dotted()
+        .max_entries(max_entries)
+        .threads(4);
+
+    let sequential_paths = sequential
+        .walk_dir()
+        .unwrap()
+        .into_iter()
+        .map(|e| e.dirent.path())
+        .collect::<Vec<std::path::PathBuf>>();
+    let parallel_paths = parallel
+        .walk_dir()
+        .unwrap()
+        .into_iter()
+        .map(|e| e.dirent.path())
+        .collect::<Vec<std::path::PathBuf>>();
+
+    assert_eq!(sequential_paths, parallel_paths);
+}
+
+#[test]
+fn should_produce_same_errors_with_threads_and_max_entries_and_follow_links() {
+    use std::os::unix::fs::symlink;
+
+    let dir = std::env::temp_dir().join("dir_walker_threads_errors_test");
+    let a = dir.join("a");
+    let b = dir.join("b");
+    let c = dir.join("c");
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&a).unwrap();
+    std::fs::create_dir_all(&b).unwrap();
+    std::fs::create_dir_all(&c).unwrap();
+    std::fs::write(a.join("x.txt"), b"").unwrap();
+    std::fs::write(b.join("x.txt"), b"").unwrap();
+
+    // "c/loop" links back to "dir", which is a cycle, but it sorts after "a"
+    // and "b" so a small enough max_entries truncates the walk before ever
+    // reaching it.
+    symlink(&dir, c.join("loop")).unwrap();
+
+    // Sweep max_entries across the whole range of entry counts so some
+    // values truncate before "c/loop" and some truncate after it.
+    for max_entries in 1..=7 {
+        let mut sequential = Walker::new(&dir)
+            .follow_links(true)
+            .max_entries(max_entries);
+        let mut parallel = Walker::new(&dir)
+            .follow_links(true)
+            .max_entries(max_entries)
+            .threads(4);
+
+        let sequential_errors = sequential
+            .walk_dir()
+            .unwrap()
+            .errors
+            .into_iter()
+            .map(|(path, _)| path)
+            .collect::<Vec<std::path::PathBuf>>();
+        let parallel_errors = parallel
+            .walk_dir()
+            .unwrap()
+            .errors
+            .into_iter()
+            .map(|(path, _)| path)
+            .collect::<Vec<std::path::PathBuf>>();
+
+        assert_eq!(
+            sequential_errors, parallel_errors,
+            "mismatch at max_entries = {max_entries}"
+        );
+    }
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn should_respect_gitignore() {
+    let dir = std::env::temp_dir().join("dir_walker_gitignore_test");
+    let nested = dir.join("nested");
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&nested).unwrap();
+
+    std::fs::write(dir.join(".gitignore"), "*.log\nnested/\n!nested/keep.log\n").unwrap();
+    std::fs::write(dir.join("keep.txt"), b"").unwrap();
+    std::fs::write(dir.join("debug.log"), b"").unwrap();
+    std::fs::write(nested.join("keep.log"), b"").unwrap();
+    std::fs::write(nested.join("other.txt"), b"").unwrap();
+
+    let entries = Walker::new(&dir).respect_gitignore(true).walk_dir().unwrap();
+
+    let names = entries
+        .into_iter()
+        .map(|e| e.dirent.file_name().to_string_lossy().to_string())
+        .collect::<Vec<String>>();
+
+    assert!(names.contains(&"keep.txt".to_string()));
+    assert!(!names.contains(&"debug.log".to_string()));
+    // "nested/" itself is ignored, so its children are pruned without being read,
+    // even though "nested/keep.log" is individually re-included further down.
+    assert!(!names.contains(&"nested".to_string()));
+    assert!(!names.contains(&"keep.log".to_string()));
+    assert!(!names.contains(&"other.txt".to_string()));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+#[cfg(unix)]
+fn should_collect_errors_instead_of_aborting() {
+    use std::os::unix::fs::PermissionsExt;
+
+    // Permission checks are bypassed for root, so this test is a no-op when run as root.
+    let is_root = std::process::Command::new("id")
+        .arg("-u")
+        .output()
+        .map(|out| String::from_utf8_lossy(&out.stdout).trim() == "0")
+        .unwrap_or(false);
+    if is_root {
+        return;
+    }
+
+    let dir = std::env::temp_dir().join("dir_walker_ignore_errors_test");
+    let locked = dir.join("locked");
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&locked).unwrap();
+    std::fs::write(dir.join("readable.txt"), b"").unwrap();
+    std::fs::set_permissions(&locked, std::fs::Permissions::from_mode(0o000)).unwrap();
+
+    // Without `ignore_errors`, the unreadable directory aborts the whole walk.
+    assert!(Walker::new(&dir).walk_dir().is_err());
+
+    // With `ignore_errors`, the walk completes and records the failure instead.
+    let entries = Walker::new(&dir).ignore_errors(true).walk_dir().unwrap();
+    assert_eq!(entries.errors.len(), 1);
+    assert_eq!(entries.errors[0].0, locked);
+
+    let names = entries
+        .into_iter()
+        .map(|e| e.dirent.file_name().to_string_lossy().to_string())
+        .collect::<Vec<String>>();
+
+    assert!(names.contains(&"readable.txt".to_string()));
+
+    std::fs::set_permissions(&locked, std::fs::Permissions::from_mode(0o755)).unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+#[cfg(unix)]
+fn should_follow_links_without_looping() {
+    use std::os::unix::fs::symlink;
+
+    let dir = std::env::temp_dir().join("dir_walker_follow_links_test");
+    let nested = dir.join("nested");
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&nested).unwrap();
+    std::fs::write(nested.join("file.txt"), b"hello").unwrap();
+
+    // "nested/loop" links back to "dir", which would hang an unguarded walk.
+    symlink(&dir, nested.join("loop")).unwrap();
+
+    let entries = Walker::new(&dir).follow_links(true).walk_dir().unwrap();
+
+    // the pruned "nested/loop" cycle is surfaced rather than silently dropped
+    let loop_path = nested.join("loop");
+    assert!(entries.errors.iter().any(|(path, _)| path == &loop_path));
+
+    let items = entries.into_iter().collect::<Vec<EntryItem>>();
+
+    let file_txt = nested.join("file.txt").canonicalize().unwrap();
+    assert!(items.iter().any(|e| e.dirent.path() == file_txt));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
 #[test]
 fn should_walk_single_file() {
     let entries = Walker::new("./src/lib.rs").walk_dir().unwrap();
@@ -111,3 +406,17 @@ fn should_walk_single_file() {
     assert_eq!(entries.len(), 1);
     assert_eq!(entries.into_iter().next().unwrap(), target_entry)
 }
+
+#[test]
+fn should_walk_single_file_with_threads() {
+    let entries = Walker::new("./src/lib.rs").threads(4).walk_dir().unwrap();
+    let entries = entries
+        .into_iter()
+        .map(|e| e.dirent.path())
+        .collect::<Vec<std::path::PathBuf>>();
+
+    let target_entry = Path::new("./src/lib.rs").canonicalize().unwrap();
+
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries.into_iter().next().unwrap(), target_entry)
+}